@@ -3,34 +3,122 @@ use std::marker::PhantomData;
 
 use std::cell::RefCell;
 
+use std::any::TypeId;
+use std::collections::HashMap;
+
+/// Errors that can occur while converting fuel into energy.
 #[derive(Eq, PartialEq, Debug, Clone, Copy)]
-pub struct Joule(pub u32);
-#[derive(Eq, PartialEq, Debug, Clone, Copy)]
-pub struct Calorie(pub u32);
+pub enum EnergyError {
+    /// An intermediate arithmetic operation would have exceeded the carrier's maximum value.
+    Overflow,
+    /// A `MeteredProvider`'s `FuelMeter` has run dry.
+    OutOfFuel,
+}
+
+/// A checked arithmetic newtype over some carrier integer type (`u64` unless otherwise
+/// specified).
+///
+/// Implementors only ever combine with another value of the exact same type, so e.g. a `Joule`
+/// can never accidentally be added to a `Calorie` without first going through a `From`
+/// conversion. All arithmetic is either `checked_*` (returning `None` on overflow) or
+/// `saturating_*` (clamping to [`GasAlgebra::max_value`]) so that nothing silently wraps.
+pub trait GasAlgebra<C = u64> {
+    /// Construct a new instance from a raw carrier value.
+    fn new(value: C) -> Self;
+
+    /// Read the raw carrier value back out.
+    fn get(&self) -> C;
+
+    /// The largest representable value for this carrier.
+    fn max_value() -> Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>
+    where
+        Self: Sized;
+    fn checked_sub(self, other: Self) -> Option<Self>
+    where
+        Self: Sized;
+    fn checked_mul(self, other: Self) -> Option<Self>
+    where
+        Self: Sized;
+    fn checked_div(self, other: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+}
 
-pub type BTU = u32;
+macro_rules! gas_algebra_newtype {
+    ($name:ident) => {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        pub struct $name(pub u64);
+
+        impl GasAlgebra for $name {
+            fn new(value: u64) -> Self {
+                Self(value)
+            }
+
+            fn get(&self) -> u64 {
+                self.0
+            }
+
+            fn max_value() -> Self {
+                Self(u64::MAX)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Self)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Self)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.0.checked_mul(other.0).map(Self)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                self.0.checked_div(other.0).map(Self)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                Self(self.0.saturating_add(other.0))
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                Self(self.0.saturating_mul(other.0))
+            }
+        }
+    };
+}
+
+gas_algebra_newtype!(Joule);
+gas_algebra_newtype!(Calorie);
+gas_algebra_newtype!(BTU);
 
 impl From<Joule> for BTU {
     fn from(j: Joule) -> Self {
-        j.0 / 1055
+        BTU::new(j.get() / 1055)
     }
 }
 
 impl From<BTU> for Joule {
     fn from(b: BTU) -> Self {
-        Self(b * 1055)
+        Joule::new(b.get().saturating_mul(1055))
     }
 }
 
 impl From<Calorie> for BTU {
     fn from(c: Calorie) -> Self {
-        c.0 / 251
+        BTU::new(c.get() / 251)
     }
 }
 
 impl From<BTU> for Calorie {
     fn from(b: BTU) -> Self {
-        Calorie(b * 251)
+        Calorie::new(b.get().saturating_mul(251))
     }
 }
 
@@ -39,88 +127,157 @@ impl From<BTU> for Calorie {
 /// A technology for storing energy for later consumption.
 pub trait Fuel {
     /// The output unit of the energy density.
-    type Output: Into<BTU> + From<BTU>;
+    type Output: GasAlgebra + Into<BTU> + From<BTU>;
 
     /// The amount of energy contained in a single unit of fuel.
-    fn energy_density() -> Self::Output;
+    fn energy_density(&self) -> Self::Output;
+
+    /// The flue-gas dewpoint, in Celsius, below which a condensing boiler burning this fuel
+    /// reclaims latent heat from the exhaust. `None` for fuels with no meaningful condensing
+    /// behavior (e.g. solid or electrochemical fuels).
+    fn dewpoint_c() -> Option<f64> {
+        None
+    }
 }
 
+#[derive(Clone, Copy, Default)]
 pub struct Diesel;
 impl Fuel for Diesel {
     type Output = Joule;
-    fn energy_density() -> Self::Output {
-        Self::Output::from(100)
+    fn energy_density(&self) -> Self::Output {
+        Self::Output::from(BTU::new(100))
     }
 }
 
+#[derive(Clone, Copy, Default)]
 pub struct LithiumBattery;
 impl Fuel for LithiumBattery {
     type Output = Calorie;
-    fn energy_density() -> Self::Output {
-        Self::Output::from(200)
+    fn energy_density(&self) -> Self::Output {
+        Self::Output::from(BTU::new(200))
     }
 }
 
+#[derive(Clone, Copy, Default)]
 pub struct Uranium;
 impl Fuel for Uranium {
     type Output = Joule;
-    fn energy_density() -> Self::Output {
-        Self::Output::from(1000)
+    fn energy_density(&self) -> Self::Output {
+        Self::Output::from(BTU::new(1000))
+    }
+}
+
+/// Mains-supplied natural gas, as burned by a condensing boiler.
+#[derive(Clone, Copy, Default)]
+pub struct MainsGas;
+impl Fuel for MainsGas {
+    type Output = BTU;
+    fn energy_density(&self) -> Self::Output {
+        BTU::new(1000)
+    }
+    fn dewpoint_c() -> Option<f64> {
+        Some(52.2)
+    }
+}
+
+/// Liquefied petroleum gas, as burned by a condensing boiler.
+#[derive(Clone, Copy, Default)]
+pub struct LPG;
+impl Fuel for LPG {
+    type Output = BTU;
+    fn energy_density(&self) -> Self::Output {
+        BTU::new(2500)
+    }
+    fn dewpoint_c() -> Option<f64> {
+        Some(48.3)
     }
 }
 
 /// A container for any fuel type.
+#[derive(Clone)]
 pub struct FuelContainer<F: Fuel> {
     /// The amount of fuel.
     amount: u32,
-    /// NOTE: Fuel doesn't really have any methods that require `&self` on it,
-    /// so any information that we can get, we can get from `F` as **TYPE**, we don't really need
-    /// to store an instance of `F`, like `fuel: F` as a struct field. But to satisfy the compiler,
-    /// we must use `F` somewhere.
-    /// Thus, this is the perfect use case of `PhantomData`.
-    _marker: PhantomData<F>,
+    /// The fuel descriptor itself. Most fuels are stateless marker types (`Diesel`, `Uranium`,
+    /// ...), but stateful fuels like `Blend` carry real runtime configuration that
+    /// `energy_density` needs, so we hold an actual instance rather than a `PhantomData<F>`.
+    fuel: F,
 }
 
-impl<F: Fuel> FuelContainer<F> {
+impl<F: Fuel + Default> FuelContainer<F> {
+    /// Construct a container for `amount` units of the fuel's default configuration. Stateful
+    /// fuels that don't have a meaningful default should use [`Self::with_fuel`] instead.
     pub fn new(amount: u32) -> Self {
         Self {
             amount,
-            _marker: Default::default(),
+            fuel: F::default(),
         }
     }
 }
 
+impl<F: Fuel> FuelContainer<F> {
+    /// Construct a container for `amount` units of a specific, already-configured fuel
+    /// instance.
+    pub fn with_fuel(amount: u32, fuel: F) -> Self {
+        Self { amount, fuel }
+    }
+
+    /// The amount of fuel held.
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+}
+
 /// Something that can provide energy from a given `F` fuel type, like a power-plant.
 pub trait ProvideEnergy<F: Fuel> {
-    /// Consume the fuel container and return the created energy, based on the power density of the
-    /// fuel and potentially other factors.
-    fn provide_energy(&self, f: FuelContainer<F>) -> <F as Fuel>::Output;
+    /// Consume the fuel container and return the created energy, based on the power density of
+    /// the fuel and potentially other factors.
+    ///
+    /// Returns `Err(EnergyError::Overflow)` if any intermediate multiplication would exceed the
+    /// carrier's maximum value.
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError>;
 
     /// Convert the amount of fuel in `f` with an exact efficiency of `e`.
     ///
     /// NOTE: all efficiencies are interpreted as u8 values that can be at most 100, and represent a
     /// percent. If an efficiency above 100 is supplied, the code should treat it as 100. That is to
     /// say that the efficiency is "saturating" at 100%.
-
-    fn provide_energy_with_efficiency(&self, f: FuelContainer<F>, e: u8) -> <F as Fuel>::Output {
-        let real_e = if e > 100 {100} else {e};
-        let energy = (f.amount * F::energy_density().into() * (real_e as u32)) / 100;
-        F::Output::from(energy)
-
+    fn provide_energy_with_efficiency(
+        &self,
+        f: FuelContainer<F>,
+        e: u8,
+    ) -> Result<F::Output, EnergyError> {
+        let real_e = if e > 100 { 100 } else { e };
+        let amount = F::Output::new(f.amount as u64);
+        let energy = amount
+            .checked_mul(f.fuel.energy_density())
+            .ok_or(EnergyError::Overflow)?
+            .checked_mul(F::Output::new(real_e as u64))
+            .ok_or(EnergyError::Overflow)?
+            .checked_div(F::Output::new(100))
+            .ok_or(EnergyError::Overflow)?;
+        Ok(energy)
     }
 
+    fn provide_energy_ideal(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
+        let amount = F::Output::new(f.amount as u64);
+        amount
+            .checked_mul(f.fuel.energy_density())
+            .ok_or(EnergyError::Overflow)
+    }
 
-    fn provide_energy_ideal(&self, f: FuelContainer<F>) -> <F as Fuel>::Output {
-        let energy = f.amount * F::energy_density().into();
-        F::Output::from(energy)
+    /// Like [`Self::provide_energy_ideal`], but clamps to [`GasAlgebra::max_value`] instead of
+    /// reporting an overflow, for callers who prefer the old "always get a total" behavior.
+    fn provide_energy_saturating(&self, f: FuelContainer<F>) -> F::Output {
+        let amount = F::Output::new(f.amount as u64);
+        amount.saturating_mul(f.fuel.energy_density())
     }
-    
 }
 
 /// A nuclear reactor that can only consume `Uranium` and provide energy with 99% efficiency.
 pub struct NuclearReactor;
 impl<F: Fuel> ProvideEnergy<F> for NuclearReactor {
-    fn provide_energy(&self, f: FuelContainer<F>) -> <F as Fuel>::Output {
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
         self.provide_energy_with_efficiency(f, 99)
     }
 }
@@ -128,33 +285,137 @@ impl<F: Fuel> ProvideEnergy<F> for NuclearReactor {
 /// A combustion engine that can only consume `Diesel`.
 ///
 /// The `DECAY` const is interpreted as such: per every `DECAY` times `provide_energy` is
-/// called on an instance of this type, the efficiency should reduce by one. 
+/// called on an instance of this type, the efficiency should reduce by one. Built on top of
+/// [`FuelMeter`]: a meter holding `DECAY` call-ticks stands in for the old raw counter, and gets
+/// reset (with the efficiency notched down) once it runs dry.
 pub struct InternalCombustion<const DECAY: u32>{
     efficiency: RefCell::<u8>,
-    count: RefCell::<u32>
+    ticks: RefCell::<FuelMeter>,
 }
 
 impl<const DECAY: u32> InternalCombustion<DECAY> {
     pub fn new(efficiency: u8) -> Self {
         Self {
             efficiency: RefCell::new(if efficiency>100 {100} else {efficiency}),
-            count: RefCell::new(0)
+            ticks: RefCell::new(FuelMeter::new(DECAY as u64)),
         }
     }
 }
 
 impl<const DECAY: u32, F: Fuel> ProvideEnergy<F> for InternalCombustion<DECAY> {
-    fn provide_energy(&self, f: FuelContainer<F>) -> <F as Fuel>::Output {
-        *self.count.borrow_mut() += 1;
-        if *self.count.borrow() > DECAY  {
-            *self.count.borrow_mut() = 0;
-            *self.efficiency.borrow_mut() -= 1;
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
+        {
+            let mut ticks = self.ticks.borrow_mut();
+            if ticks.is_exhausted() {
+                *ticks = FuelMeter::new(DECAY as u64);
+                let mut efficiency = self.efficiency.borrow_mut();
+                *efficiency = efficiency.saturating_sub(1);
+            } else {
+                ticks.consume(1);
+            }
         }
         self.provide_energy_with_efficiency(f, *self.efficiency.borrow())
-        
+
+    }
+
+
+}
+
+/// Tracks a budget of energy units available for consumption, generalizing the ad-hoc decay
+/// counter that `InternalCombustion` hard-codes into something any provider can reuse.
+pub struct FuelMeter {
+    /// The total reserve of energy units the meter started with.
+    reserve: u64,
+    /// How many units have been drawn so far. Signed so that `refuel` can claw back
+    /// over-consumption without the reserve itself ever going negative.
+    consumed: i64,
+}
+
+impl FuelMeter {
+    pub fn new(reserve: u64) -> Self {
+        Self { reserve, consumed: 0 }
+    }
+
+    /// Energy units still available to draw from.
+    pub fn remaining(&self) -> u64 {
+        self.reserve.saturating_add_signed(-self.consumed)
     }
 
+    /// Record that `units` of energy were drawn from the meter.
+    pub fn consume(&mut self, units: u64) {
+        self.consumed = self.consumed.saturating_add(units as i64);
+    }
+
+    /// Add leftover reserve back into the active pool. Only positive amounts have any effect;
+    /// refuelling by more than has been consumed raises `remaining` above the starting reserve.
+    pub fn refuel(&mut self, extra: i64) {
+        if extra > 0 {
+            self.consumed = self.consumed.saturating_sub(extra);
+        }
+    }
+
+    /// Whether the meter has nothing left to draw from.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+/// When a `MeteredProvider` debits its `FuelMeter` relative to the `provide_energy` call it
+/// wraps.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum ConsumptionMode {
+    /// Deduct the full cost of a call up front, erroring immediately (and deducting nothing) if
+    /// the reserve can't cover that cost.
+    Eager,
+    /// Let a call complete regardless of the reserve, only reporting exhaustion on the *next*
+    /// call.
+    Lazy,
+}
+
+/// Wraps any `ProvideEnergy<F>` and debits a `FuelMeter` per call, turning the crate's one-off
+/// decay hack into a general budgeted-execution model usable by every provider.
+pub struct MeteredProvider<P> {
+    provider: P,
+    meter: RefCell<FuelMeter>,
+    mode: ConsumptionMode,
+}
+
+impl<P> MeteredProvider<P> {
+    pub fn new(provider: P, reserve: u64, mode: ConsumptionMode) -> Self {
+        Self {
+            provider,
+            meter: RefCell::new(FuelMeter::new(reserve)),
+            mode,
+        }
+    }
+
+    /// Energy units still available to the wrapped provider.
+    pub fn remaining(&self) -> u64 {
+        self.meter.borrow().remaining()
+    }
+}
 
+impl<F: Fuel, P: ProvideEnergy<F>> ProvideEnergy<F> for MeteredProvider<P> {
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
+        let cost = f.amount as u64;
+        match self.mode {
+            ConsumptionMode::Eager => {
+                if cost > self.meter.borrow().remaining() {
+                    return Err(EnergyError::OutOfFuel);
+                }
+                self.meter.borrow_mut().consume(cost);
+                self.provider.provide_energy(f)
+            }
+            ConsumptionMode::Lazy => {
+                if self.meter.borrow().is_exhausted() {
+                    return Err(EnergyError::OutOfFuel);
+                }
+                let result = self.provider.provide_energy(f);
+                self.meter.borrow_mut().consume(cost);
+                result
+            }
+        }
+    }
 }
 
 /// A hypothetical device that can, unlike the `InternalCombustion`, consume **any fuel** that's of
@@ -165,11 +426,74 @@ pub struct OmniGenerator<const EFFICIENCY: u8>;
 
 // NOTE: implement `ProvideEnergy` for `OmniGenerator` using only one `impl` block.
 impl<const EFFICIENCY: u8, F: Fuel> ProvideEnergy<F> for OmniGenerator<EFFICIENCY> {
-    fn provide_energy(&self, f: FuelContainer<F>) -> <F as Fuel>::Output {
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
         self.provide_energy_with_efficiency(f, EFFICIENCY)
     }
 }
 
+/// Something whose efficiency varies as a function of an operating condition, rather than being a
+/// flat percent.
+pub trait EfficiencyModel {
+    /// The instantaneous efficiency, as a fraction in `[0.0, 1.0]`, at the given boiler return
+    /// temperature in Celsius.
+    fn efficiency_at(&self, return_temp_c: f64) -> f64;
+}
+
+/// A boiler-like energy provider whose efficiency follows condensing-boiler physics instead of a
+/// flat percent: below the fuel's dewpoint, efficiency rises as the flue gas condenses and
+/// reclaims latent heat; above it, efficiency falls off linearly. Fuels with no dewpoint
+/// (`Fuel::dewpoint_c` returns `None`) fall back to a flat efficiency.
+pub struct TemperatureDependent<F: Fuel> {
+    /// The flat efficiency (as a percent) used for fuels that don't condense.
+    flat_efficiency: u8,
+    /// The current boiler return temperature, in Celsius, used by `provide_energy`.
+    return_temp_c: RefCell<f64>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Fuel> TemperatureDependent<F> {
+    pub fn new(flat_efficiency: u8, return_temp_c: f64) -> Self {
+        Self {
+            flat_efficiency: if flat_efficiency > 100 { 100 } else { flat_efficiency },
+            return_temp_c: RefCell::new(return_temp_c),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Update the boiler return temperature used by subsequent `provide_energy` calls.
+    pub fn set_return_temp_c(&self, return_temp_c: f64) {
+        *self.return_temp_c.borrow_mut() = return_temp_c;
+    }
+}
+
+impl<F: Fuel> EfficiencyModel for TemperatureDependent<F> {
+    fn efficiency_at(&self, return_temp_c: f64) -> f64 {
+        let Some(dewpoint) = F::dewpoint_c() else {
+            return self.flat_efficiency as f64 / 100.0;
+        };
+
+        let condensing = |t: f64| -0.00007 * t * t + 0.0017 * t + 0.979;
+        let efficiency = if return_temp_c < dewpoint {
+            condensing(return_temp_c)
+        } else {
+            // Anchor the linear falloff to the condensing curve's value at the dewpoint so the
+            // efficiency doesn't jump discontinuously at the boundary.
+            let continuity_const = condensing(dewpoint) + 0.0006 * dewpoint;
+            -0.0006 * return_temp_c + continuity_const
+        };
+
+        efficiency.clamp(0.0, 1.0)
+    }
+}
+
+impl<F: Fuel> ProvideEnergy<F> for TemperatureDependent<F> {
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
+        let efficiency = self.efficiency_at(*self.return_temp_c.borrow());
+        let percent = (efficiency * 100.0).round().clamp(0.0, 100.0) as u8;
+        self.provide_energy_with_efficiency(f, percent)
+    }
+}
+
 /// A type that can wrap two different fuel types and mix them together.
 ///
 /// The energy density of the new fuel type is the average of the two given, once converted to BTU.
@@ -177,12 +501,19 @@ impl<const EFFICIENCY: u8, F: Fuel> ProvideEnergy<F> for OmniGenerator<EFFICIENC
 /// This can represent a new fuel type, thus it is implemented as `Fuel`.
 pub struct Mixed<F1: Fuel, F2: Fuel>(PhantomData<(F1, F2)>);
 
-impl<F1: Fuel, F2: Fuel> Fuel for Mixed<F1, F2> {
+impl<F1: Fuel, F2: Fuel> Default for Mixed<F1, F2> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F1: Fuel + Default, F2: Fuel + Default> Fuel for Mixed<F1, F2> {
     type Output = BTU;
 
-    fn energy_density() -> Self::Output {
-        let mixed_energy = (F1::energy_density().into() + F2::energy_density().into())/2;
-        Self::Output::from(mixed_energy)
+    fn energy_density(&self) -> Self::Output {
+        let a: BTU = F1::default().energy_density().into();
+        let b: BTU = F2::default().energy_density().into();
+        BTU::new(a.saturating_add(b).get() / 2)
     }
 }
 
@@ -190,15 +521,99 @@ impl<F1: Fuel, F2: Fuel> Fuel for Mixed<F1, F2> {
 // that is more influences by one type than the other.
 //
 // For example, you have a mixer of F1, F2, and some coefficient C1, where the energy density of the
-// mixture is `F1 * C1 + F2 * (1 - C1) )` where `C1` is a ratio 
+// mixture is `F1 * C1 + F2 * (1 - C1) )` where `C1` is a ratio
 pub struct CustomMixed<const C: u8, F1, F2>(PhantomData<(F1, F2)>);
-impl<const C: u8, F1: Fuel, F2: Fuel> Fuel for CustomMixed<C, F1, F2> {
+
+impl<const C: u8, F1, F2> Default for CustomMixed<C, F1, F2> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<const C: u8, F1: Fuel + Default, F2: Fuel + Default> Fuel for CustomMixed<C, F1, F2> {
+    type Output = BTU;
+
+    fn energy_density(&self) -> Self::Output {
+        let a: BTU = F1::default().energy_density().into();
+        let b: BTU = F2::default().energy_density().into();
+        let c = C as u64;
+        let weighted_a = a.saturating_mul(BTU::new(c)).get() / 100;
+        let weighted_b = b.saturating_mul(BTU::new(100u64.saturating_sub(c))).get() / 100;
+        BTU::new(weighted_a.saturating_add(weighted_b))
+    }
+}
+
+/// Errors that can occur while assembling or querying a `Blend`.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum BlendError {
+    /// A component's output unit doesn't match the unit already declared by an earlier
+    /// component in this blend, the same way you can't combine quantities from different
+    /// phases.
+    IncompatibleUnit,
+    /// The blend's energy density was requested before any component was added.
+    EmptyBlend,
+}
+
+/// A runtime-assembled fuel blend of any number of heterogeneous fuels, each contributing a
+/// fraction of the mix. Unlike `Mixed`/`CustomMixed`, which fix exactly two fuels and one ratio
+/// at compile time, a `Blend`'s components and ratios are decided at runtime.
+#[derive(Clone, Default)]
+pub struct Blend {
+    /// Each component's energy density (already converted to BTU) and its fraction of the
+    /// blend. The first component added declares the blend's carrier unit; later components
+    /// must share it.
+    components: Vec<(BTU, f64)>,
+    carrier: Option<TypeId>,
+}
+
+impl Blend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a component with the given energy density and fraction (fractions don't need to be
+    /// pre-normalized; `energy_density` normalizes them). Rejects a component whose output unit
+    /// doesn't match the unit already declared by earlier components in this blend.
+    pub fn try_add<O>(&mut self, density: O, fraction: f64) -> Result<(), BlendError>
+    where
+        O: GasAlgebra + Into<BTU> + 'static,
+    {
+        let unit = TypeId::of::<O>();
+        match self.carrier {
+            Some(carrier) if carrier != unit => return Err(BlendError::IncompatibleUnit),
+            _ => self.carrier = Some(unit),
+        }
+
+        self.components.push((density.into(), fraction));
+        Ok(())
+    }
+
+    /// The fraction-weighted average energy density of the blend's components, after
+    /// normalizing their fractions so they sum to 1.0.
+    pub fn checked_energy_density(&self) -> Result<BTU, BlendError> {
+        let total_fraction: f64 = self.components.iter().map(|(_, fraction)| fraction).sum();
+        if self.components.is_empty() || total_fraction <= 0.0 {
+            return Err(BlendError::EmptyBlend);
+        }
+
+        let weighted: f64 = self
+            .components
+            .iter()
+            .map(|(density, fraction)| density.get() as f64 * (fraction / total_fraction))
+            .sum();
+
+        Ok(BTU::new(weighted.round() as u64))
+    }
+}
+
+impl Fuel for Blend {
     type Output = BTU;
 
-    fn energy_density() -> Self::Output {
-        
-        let custom_energy = (F1::energy_density().into() * (C as u32)/100) + (F2::energy_density().into() * (100-(C as u32))/100);
-        Self::Output::from(custom_energy)
+    /// Falls back to zero for an empty blend, consistent with the crate's distinction between
+    /// "no energy" and "arithmetic blew up"; use [`Self::checked_energy_density`] to observe
+    /// `BlendError::EmptyBlend` explicitly.
+    fn energy_density(&self) -> Self::Output {
+        self.checked_energy_density().unwrap_or(BTU::new(0))
     }
 }
 
@@ -207,7 +622,8 @@ impl<const C: u8, F1: Fuel, F2: Fuel> Fuel for CustomMixed<C, F1, F2> {
 /// A function that returns the energy produced by the `OmniGenerator` with efficiency of 80%, when
 /// the fuel type is an even a mix of `Diesel` as `LithiumBattery`;
 pub fn omni_80_energy(amount: u32) -> BTU {
-    amount * 80 / 100
+    let mix_density = Mixed::<Diesel, LithiumBattery>::default().energy_density().get();
+    BTU::new((amount as u64) * mix_density * 80 / 100)
 }
 
 
@@ -219,7 +635,7 @@ impl IsRenewable for LithiumBattery {}
 /// It has perfect efficiency.
 pub struct GreenEngine<F: Fuel + IsRenewable>(pub PhantomData<F>);
 impl<F: Fuel + IsRenewable> ProvideEnergy<F> for GreenEngine<F> {
-    fn provide_energy(&self, f: FuelContainer<F>) -> <F as Fuel>::Output {
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
         self.provide_energy_ideal(f)
     }
 }
@@ -229,10 +645,261 @@ impl<F: Fuel + IsRenewable> ProvideEnergy<F> for GreenEngine<F> {
 /// It has perfect efficiency.
 pub struct BritishEngine<F: Fuel>(pub PhantomData<F>);
 impl<F: Fuel<Output = BTU>> ProvideEnergy<F> for BritishEngine<F> {
-    fn provide_energy(&self, f: FuelContainer<F>) -> <F as Fuel>::Output {
+    fn provide_energy(&self, f: FuelContainer<F>) -> Result<F::Output, EnergyError> {
         self.provide_energy_ideal(f)
     }
-    
+
+}
+
+/// Whether a `PowerPlant` should try to dispatch a unit before or after the rest of its fleet.
+/// Batteries and renewables typically get `First`.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, PartialOrd, Ord)]
+pub enum DispatchPriority {
+    First,
+    Normal,
+}
+
+/// A single dispatchable unit inside a `PowerPlant`: a `ProvideEnergy` paired with the fuel type
+/// it burns, type-erased so heterogeneous units can live in one plant.
+trait DispatchableUnit {
+    /// The fuel type this unit consumes, used to look its stock up in the plant's inventory.
+    fn fuel_type_id(&self) -> TypeId;
+
+    fn priority(&self) -> DispatchPriority;
+
+    /// Burn just enough of `available` fuel to cover `demand`, or all of it if the unit can't
+    /// produce that much on its own, and report the energy actually produced and the fuel
+    /// actually consumed.
+    fn dispatch(&self, available: u32, demand: u64) -> (BTU, u32);
+}
+
+struct PlantUnit<F: Fuel, P: ProvideEnergy<F>> {
+    provider: P,
+    fuel: F,
+    priority: DispatchPriority,
+}
+
+impl<F: Fuel + Clone + 'static, P: ProvideEnergy<F>> DispatchableUnit for PlantUnit<F, P> {
+    fn fuel_type_id(&self) -> TypeId {
+        TypeId::of::<F>()
+    }
+
+    fn priority(&self) -> DispatchPriority {
+        self.priority
+    }
+
+    fn dispatch(&self, available: u32, demand: u64) -> (BTU, u32) {
+        if available == 0 || demand == 0 {
+            return (BTU::new(0), 0);
+        }
+
+        let container = FuelContainer::with_fuel(available, self.fuel.clone());
+        let full_energy: BTU = match self.provider.provide_energy(container) {
+            Ok(energy) => energy.into(),
+            Err(_) => return (BTU::new(0), 0),
+        };
+
+        if full_energy.get() <= demand {
+            return (full_energy, available);
+        }
+
+        // Burning all of `available` would overshoot `demand`; scale the draw down
+        // proportionally instead, assuming energy output scales with fuel amount.
+        // Round the draw up so the whole units burned always cover `demand`.
+        let full = full_energy.get() as u128;
+        let needed_fuel = ((available as u128 * demand as u128 + full - 1) / full)
+            .clamp(1, available as u128) as u32;
+        let produced = BTU::new(
+            ((full_energy.get() as u128 * needed_fuel as u128) / available as u128) as u64,
+        );
+        (produced, needed_fuel)
+    }
+}
+
+/// A composite power source aggregating multiple, possibly differently-fueled, `ProvideEnergy`
+/// units over one shared, typed fuel inventory.
+///
+/// Dispatching enables only the units whose fuel is actually in stock, drawing from the
+/// inventory to satisfy demand and skipping (rather than erroring on) units that can't run on
+/// what's available, like a vehicle choosing which of its engines to run.
+pub struct PowerPlant {
+    units: Vec<Box<dyn DispatchableUnit>>,
+    inventory: HashMap<TypeId, u32>,
+}
+
+impl PowerPlant {
+    pub fn new() -> Self {
+        Self {
+            units: Vec::new(),
+            inventory: HashMap::new(),
+        }
+    }
+
+    /// Register a dispatchable unit burning `amount` units of a specific, already-configured
+    /// fuel instance, with the given dispatch priority.
+    pub fn add_unit<F: Fuel + Clone + 'static, P: ProvideEnergy<F> + 'static>(
+        &mut self,
+        provider: P,
+        fuel: F,
+        priority: DispatchPriority,
+    ) {
+        self.units.push(Box::new(PlantUnit::<F, P> {
+            provider,
+            fuel,
+            priority,
+        }));
+    }
+
+    /// Stock the plant's inventory with `amount` more units of fuel `F`.
+    pub fn stock<F: Fuel + 'static>(&mut self, amount: u32) {
+        *self.inventory.entry(TypeId::of::<F>()).or_insert(0) += amount;
+    }
+
+    /// The amount of fuel `F` still sitting in the inventory, e.g. to observe how much a
+    /// `dispatch` call left behind after a partial draw.
+    pub fn remaining<F: Fuel + 'static>(&self) -> u32 {
+        self.inventory.get(&TypeId::of::<F>()).copied().unwrap_or(0)
+    }
+
+    /// Try to meet `demand`, bringing registered units online in priority order (units added
+    /// with `DispatchPriority::First` before `Normal` ones) until demand is met or the plant runs
+    /// out of engageable units. Each engaged unit draws only as much of its available fuel as is needed to cover
+    /// what's still outstanding; a unit whose fuel isn't in stock is skipped rather than
+    /// erroring.
+    ///
+    /// Returns `(delivered, unmet)`: the total energy actually delivered, and how much of
+    /// `demand` is still outstanding.
+    pub fn dispatch(&mut self, demand: BTU) -> (BTU, BTU) {
+        let mut remaining_demand = demand.get();
+        let mut delivered = 0u64;
+
+        let mut order: Vec<usize> = (0..self.units.len()).collect();
+        order.sort_by_key(|&i| self.units[i].priority());
+
+        for i in order {
+            if remaining_demand == 0 {
+                break;
+            }
+
+            let unit = &self.units[i];
+            let fuel_id = unit.fuel_type_id();
+            let available = match self.inventory.get(&fuel_id) {
+                Some(&amount) if amount > 0 => amount,
+                _ => continue,
+            };
+
+            let (produced, consumed) = unit.dispatch(available, remaining_demand);
+            if consumed > 0 {
+                *self.inventory.get_mut(&fuel_id).unwrap() -= consumed;
+            }
+            delivered = delivered.saturating_add(produced.get());
+            remaining_demand = remaining_demand.saturating_sub(produced.get());
+        }
+
+        (BTU::new(delivered), BTU::new(remaining_demand))
+    }
+}
+
+impl Default for PowerPlant {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors that can occur while assembling a `Stage`.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum StageError {
+    /// A stage's dry mass must be positive, or `m_wet / m_dry` would be undefined or infinite.
+    NonPositiveDryMass,
+}
+
+/// One rocket stage: a dry structural mass, a fuel load, and the engine burning it.
+pub struct Stage<F: Fuel, P: ProvideEnergy<F>> {
+    dry_mass: f64,
+    fuel: FuelContainer<F>,
+    engine: P,
+}
+
+impl<F: Fuel + Clone, P: ProvideEnergy<F>> Stage<F, P> {
+    /// Build a stage. Rejects a non-positive `dry_mass`, since `m_wet / m_dry` would otherwise
+    /// be undefined or infinite.
+    pub fn new(dry_mass: f64, fuel: FuelContainer<F>, engine: P) -> Result<Self, StageError> {
+        if dry_mass <= 0.0 {
+            return Err(StageError::NonPositiveDryMass);
+        }
+        Ok(Self {
+            dry_mass,
+            fuel,
+            engine,
+        })
+    }
+
+    /// The delta-v this stage alone can contribute, via the Tsiolkovsky rocket equation:
+    /// `v_e * ln(m_wet / m_dry)`. A stage carrying no fuel contributes nothing.
+    pub fn delta_v(&self) -> f64 {
+        self.delta_v_with_dry_mass(self.dry_mass)
+    }
+
+    /// Like [`Self::delta_v`], but lets a `Rocket` substitute in the dry mass still attached at
+    /// the moment this stage fires (its own dry mass plus whatever hasn't been jettisoned yet).
+    fn delta_v_with_dry_mass(&self, dry_mass: f64) -> f64 {
+        let fuel_mass = self.fuel.amount() as f64;
+        if fuel_mass == 0.0 {
+            return 0.0;
+        }
+
+        // The effective exhaust velocity comes from how much energy the engine can wring out of
+        // a unit of fuel mass: v_e = sqrt(2 * specific_energy), with the total energy normalized
+        // through BTU (the crate's common unit) and back into Joule before dividing by the fuel
+        // mass it came from.
+        let specific_energy = match self.engine.provide_energy(self.fuel.clone()) {
+            Ok(energy) => {
+                let btu: BTU = energy.into();
+                Joule::from(btu).get() as f64 / fuel_mass
+            }
+            Err(_) => return 0.0,
+        };
+        let exhaust_velocity = (2.0 * specific_energy).sqrt();
+
+        let wet_mass = dry_mass + fuel_mass;
+        exhaust_velocity * (wet_mass / dry_mass).ln()
+    }
+}
+
+/// An ordered sequence of `Stage`s burned one after another, each stage's dry mass dropping away
+/// (jettisoned) once it's spent.
+pub struct Rocket<F: Fuel, P: ProvideEnergy<F>> {
+    stages: Vec<Stage<F, P>>,
+}
+
+impl<F: Fuel + Clone, P: ProvideEnergy<F>> Rocket<F, P> {
+    pub fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Append a stage to the end of the burn order.
+    pub fn add_stage(&mut self, stage: Stage<F, P>) {
+        self.stages.push(stage);
+    }
+
+    /// The combined delta-v of every stage firing in order. When stage `i` fires, the dry mass
+    /// of stages `0..i` has already been dropped, but the dry mass of stages `i+1..` is still
+    /// riding along on top of it as payload. The fuel those later stages carry is not counted
+    /// in that payload.
+    pub fn total_delta_v(&self) -> f64 {
+        (0..self.stages.len())
+            .map(|i| {
+                let payload_dry_mass: f64 = self.stages[i + 1..].iter().map(|s| s.dry_mass).sum();
+                self.stages[i].delta_v_with_dry_mass(self.stages[i].dry_mass + payload_dry_mass)
+            })
+            .sum()
+    }
+}
+
+impl<F: Fuel + Clone, P: ProvideEnergy<F>> Default for Rocket<F, P> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -254,13 +921,15 @@ mod tests {
         let nr = NuclearReactor;
         assert_eq!(
             nr.provide_energy(FuelContainer::<Uranium>::new(10))
+                .unwrap()
                 .to_btu(),
-            9900
+            BTU::new(9900)
         );
         assert_eq!(
             nr.provide_energy(FuelContainer::<Uranium>::new(10))
+                .unwrap()
                 .to_btu(),
-            9900
+            BTU::new(9900)
         );
     }
 
@@ -268,47 +937,59 @@ mod tests {
     fn ic_1() {
         let ic = InternalCombustion::<3>::new(120);
         assert_eq!(
-            ic.provide_energy(FuelContainer::<Diesel>::new(10)).to_btu(),
-            1000
+            ic.provide_energy(FuelContainer::<Diesel>::new(10)).unwrap().to_btu(),
+            BTU::new(1000)
         );
         assert_eq!(
-            ic.provide_energy(FuelContainer::<Diesel>::new(10)).to_btu(),
-            1000
+            ic.provide_energy(FuelContainer::<Diesel>::new(10)).unwrap().to_btu(),
+            BTU::new(1000)
         );
         assert_eq!(
-            ic.provide_energy(FuelContainer::<Diesel>::new(10)).to_btu(),
-            1000
+            ic.provide_energy(FuelContainer::<Diesel>::new(10)).unwrap().to_btu(),
+            BTU::new(1000)
         );
         assert_eq!(
-            ic.provide_energy(FuelContainer::<Diesel>::new(10)).to_btu(),
-            990
+            ic.provide_energy(FuelContainer::<Diesel>::new(10)).unwrap().to_btu(),
+            BTU::new(990)
         );
     }
 
+    #[test]
+    fn ic_efficiency_saturates_at_zero_instead_of_panicking() {
+        // DECAY=1 means every other call notches the efficiency down by one; starting it at 1
+        // drives it straight to zero and then has to stay there rather than underflowing.
+        let ic = InternalCombustion::<1>::new(1);
+        for _ in 0..5 {
+            assert!(ic.provide_energy(FuelContainer::<Diesel>::new(10)).is_ok());
+        }
+    }
+
     #[test]
     fn omni_1() {
         let og = OmniGenerator::<100>;
         assert_eq!(
             og.provide_energy(FuelContainer::<Uranium>::new(10))
+                .unwrap()
                 .to_btu(),
-            10000
+            BTU::new(10000)
         );
         assert_eq!(
-            og.provide_energy(FuelContainer::<Diesel>::new(10)).to_btu(),
-            1000
+            og.provide_energy(FuelContainer::<Diesel>::new(10)).unwrap().to_btu(),
+            BTU::new(1000)
         );
         assert_eq!(
             og.provide_energy(FuelContainer::<LithiumBattery>::new(10))
+                .unwrap()
                 .to_btu(),
-            2000
+            BTU::new(2000)
         );
     }
 
     #[test]
     fn mixed_1() {
         assert_eq!(
-            Mixed::<Diesel, LithiumBattery>::energy_density().to_btu(),
-            150
+            Mixed::<Diesel, LithiumBattery>::default().energy_density().to_btu(),
+            BTU::new(150)
         );
     }
 
@@ -316,8 +997,8 @@ mod tests {
     fn custom_mixed_1() {
         // custom with 50 is the same as Mixed.
         assert_eq!(
-            CustomMixed::<50, Diesel, LithiumBattery>::energy_density().to_btu(),
-            Mixed::<Diesel, LithiumBattery>::energy_density()
+            CustomMixed::<50, Diesel, LithiumBattery>::default().energy_density().to_btu(),
+            Mixed::<Diesel, LithiumBattery>::default().energy_density()
         );
     }
     #[test]
@@ -325,13 +1006,15 @@ mod tests {
         let gre = GreenEngine::<LithiumBattery>(PhantomData::<LithiumBattery>);
         assert_eq!(
             gre.provide_energy(FuelContainer::<LithiumBattery>::new(10))
+            .unwrap()
             .to_btu(),
-        2000
+        BTU::new(2000)
         );
         assert_eq!(
             gre.provide_energy(FuelContainer::<LithiumBattery>::new(10))
+            .unwrap()
             .to_btu(),
-        2000
+        BTU::new(2000)
         )
     }
 
@@ -340,9 +1023,251 @@ mod tests {
         let bri = BritishEngine::<Mixed<Diesel, LithiumBattery>>(PhantomData::<Mixed<Diesel, LithiumBattery>>);
         assert_eq!(
             bri.provide_energy(FuelContainer::<Mixed<Diesel, LithiumBattery>>::new(10))
+                .unwrap()
                 .to_btu(),
-            1500
+            BTU::new(1500)
         )
-        
+
+    }
+
+    /// A fuel with a deliberately huge energy density, used to exercise the overflow path
+    /// without needing unrealistic fuel amounts.
+    #[derive(Clone, Copy, Default)]
+    struct DenseFuel;
+    impl Fuel for DenseFuel {
+        type Output = Joule;
+        fn energy_density(&self) -> Self::Output {
+            Joule::new(u64::MAX / 2)
+        }
+    }
+
+    #[test]
+    fn provide_energy_overflows_on_huge_density() {
+        let nr = NuclearReactor;
+        let err = nr.provide_energy(FuelContainer::<DenseFuel>::new(10));
+        assert_eq!(err, Err(EnergyError::Overflow));
+    }
+
+    #[test]
+    fn provide_energy_saturating_clamps_instead_of_erroring() {
+        let og = OmniGenerator::<100>;
+        let clamped = og.provide_energy_saturating(FuelContainer::<DenseFuel>::new(10));
+        assert_eq!(clamped, Joule::max_value());
+    }
+
+    #[test]
+    fn temperature_dependent_condenses_below_dewpoint() {
+        let boiler = TemperatureDependent::<MainsGas>::new(90, 30.0);
+        let efficiency = boiler.efficiency_at(30.0);
+        assert!((efficiency - (-0.00007 * 30.0f64.powi(2) + 0.0017 * 30.0 + 0.979)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn temperature_dependent_is_continuous_at_the_dewpoint() {
+        let boiler = TemperatureDependent::<MainsGas>::new(90, 52.2);
+        let just_below = boiler.efficiency_at(52.2 - 0.001);
+        let just_above = boiler.efficiency_at(52.2 + 0.001);
+        assert!((just_below - just_above).abs() < 1e-4);
+    }
+
+    #[test]
+    fn temperature_dependent_falls_back_to_flat_efficiency_without_a_dewpoint() {
+        let engine = TemperatureDependent::<Diesel>::new(70, 90.0);
+        assert_eq!(engine.efficiency_at(20.0), 0.7);
+        assert_eq!(engine.efficiency_at(90.0), 0.7);
+    }
+
+    #[test]
+    fn fuel_meter_tracks_consumption_and_refuel() {
+        let mut meter = FuelMeter::new(100);
+        meter.consume(40);
+        assert_eq!(meter.remaining(), 60);
+        meter.refuel(10);
+        assert_eq!(meter.remaining(), 70);
+        meter.refuel(-1000);
+        assert_eq!(meter.remaining(), 70);
+    }
+
+    #[test]
+    fn metered_provider_eager_errors_immediately_when_exhausted() {
+        let metered = MeteredProvider::new(OmniGenerator::<100>, 5, ConsumptionMode::Eager);
+        assert_eq!(
+            metered.provide_energy(FuelContainer::<Diesel>::new(10)),
+            Err(EnergyError::OutOfFuel)
+        );
+        // Nothing was drawn, since the eager check runs before consuming.
+        assert_eq!(metered.remaining(), 5);
+    }
+
+    #[test]
+    fn power_plant_skips_units_with_no_fuel_in_stock() {
+        let mut plant = PowerPlant::new();
+        plant.add_unit::<Diesel, _>(OmniGenerator::<100>, Diesel, DispatchPriority::Normal);
+        // Deliberately never stock any Diesel.
+
+        let (delivered, unmet) = plant.dispatch(BTU::new(500));
+        assert_eq!(delivered, BTU::new(0));
+        assert_eq!(unmet, BTU::new(500));
+    }
+
+    #[test]
+    fn power_plant_dispatches_renewables_before_other_units() {
+        let mut plant = PowerPlant::new();
+        // Registered in reverse priority order, to prove dispatch reorders by priority rather
+        // than relying on registration order.
+        plant.add_unit::<Uranium, _>(NuclearReactor, Uranium, DispatchPriority::Normal);
+        plant.add_unit::<LithiumBattery, _>(
+            GreenEngine::<LithiumBattery>(PhantomData),
+            LithiumBattery,
+            DispatchPriority::First,
+        );
+
+        plant.stock::<Uranium>(1000);
+        plant.stock::<LithiumBattery>(1000);
+
+        // Demand more than the `First`-priority (renewable) unit can supply alone (its 1000
+        // units of LithiumBattery cap out at 200_000 BTU), so `dispatch` has to keep working down
+        // the priority order into the `Normal` Uranium unit to cover the rest. The Uranium unit
+        // still has fuel left over once demand is met, proving it only drew what it needed
+        // rather than burning its whole stock.
+        let (delivered, unmet) = plant.dispatch(BTU::new(249_500));
+
+        // Lithium (density 200 BTU/unit) fully drained: 1000 * 200 = 200_000 BTU.
+        // Uranium (density 1000 BTU/unit, 99% efficient) only needs 50 of its 1000 units to make
+        // up the remaining 49_500 BTU: 50 * 1000 * 99 / 100 = 49_500.
+        assert_eq!(delivered, BTU::new(200_000 + 49_500));
+        assert_eq!(unmet, BTU::new(0));
+        assert_eq!(plant.remaining::<LithiumBattery>(), 0);
+        assert_eq!(plant.remaining::<Uranium>(), 950);
+    }
+
+    #[test]
+    fn power_plant_draws_only_the_fuel_needed_to_cover_demand() {
+        let mut plant = PowerPlant::new();
+        plant.add_unit::<LithiumBattery, _>(
+            GreenEngine::<LithiumBattery>(PhantomData),
+            LithiumBattery,
+            DispatchPriority::First,
+        );
+        plant.stock::<LithiumBattery>(1000);
+
+        // A tiny demand against a unit that could supply 200_000 BTU from its full stock must
+        // only draw a sliver of fuel to cover it, leaving the rest in reserve. Fuel is only
+        // drawn in whole units, so even a single unit (200 BTU) overshoots a demand of 10, but
+        // that's still 999 units better than draining the whole stock for it.
+        let (delivered, unmet) = plant.dispatch(BTU::new(10));
+        assert_eq!(delivered, BTU::new(200));
+        assert_eq!(unmet, BTU::new(0));
+        assert_eq!(plant.remaining::<LithiumBattery>(), 999);
+    }
+
+    #[test]
+    fn metered_provider_lazy_only_errors_on_the_next_call() {
+        let metered = MeteredProvider::new(OmniGenerator::<100>, 5, ConsumptionMode::Lazy);
+        assert!(metered
+            .provide_energy(FuelContainer::<Diesel>::new(10))
+            .is_ok());
+        assert_eq!(
+            metered.provide_energy(FuelContainer::<Diesel>::new(1)),
+            Err(EnergyError::OutOfFuel)
+        );
+    }
+
+    #[test]
+    fn blend_is_the_fraction_weighted_average_of_its_components() {
+        let mut blend = Blend::new();
+        blend.try_add(Diesel.energy_density().to_btu(), 0.5).unwrap();
+        blend
+            .try_add(LithiumBattery.energy_density().to_btu(), 0.5)
+            .unwrap();
+
+        // Same components and ratio as `Mixed::<Diesel, LithiumBattery>`.
+        assert_eq!(
+            blend.checked_energy_density().unwrap(),
+            Mixed::<Diesel, LithiumBattery>::default().energy_density()
+        );
+    }
+
+    #[test]
+    fn blend_normalizes_fractions_that_do_not_sum_to_one() {
+        let mut blend = Blend::new();
+        blend.try_add(BTU::new(100), 1.0).unwrap();
+        blend.try_add(BTU::new(300), 3.0).unwrap();
+
+        // Equivalent to fractions of 0.25 and 0.75.
+        assert_eq!(blend.checked_energy_density().unwrap(), BTU::new(250));
+    }
+
+    #[test]
+    fn blend_rejects_incompatible_units() {
+        let mut blend = Blend::new();
+        blend.try_add(Joule::new(100), 0.5).unwrap();
+        assert_eq!(
+            blend.try_add(Calorie::new(100), 0.5),
+            Err(BlendError::IncompatibleUnit)
+        );
+    }
+
+    #[test]
+    fn blend_reports_empty_blend_error() {
+        let blend = Blend::new();
+        assert_eq!(blend.checked_energy_density(), Err(BlendError::EmptyBlend));
+        // `Fuel::energy_density` still has to return something; it falls back to zero.
+        assert_eq!(blend.energy_density(), BTU::new(0));
+    }
+
+    #[test]
+    fn british_engine_can_burn_a_runtime_blend() {
+        let mut blend = Blend::new();
+        blend.try_add(Diesel.energy_density().to_btu(), 0.5).unwrap();
+        blend
+            .try_add(LithiumBattery.energy_density().to_btu(), 0.5)
+            .unwrap();
+
+        let bri = BritishEngine::<Blend>(PhantomData);
+        assert_eq!(
+            bri.provide_energy(FuelContainer::with_fuel(10, blend))
+                .unwrap()
+                .to_btu(),
+            BTU::new(1500)
+        );
+    }
+
+    #[test]
+    fn stage_rejects_a_non_positive_dry_mass() {
+        let fuel = FuelContainer::<Diesel>::new(1055);
+        assert!(matches!(
+            Stage::new(0.0, fuel, OmniGenerator::<100>),
+            Err(StageError::NonPositiveDryMass)
+        ));
+    }
+
+    #[test]
+    fn stage_with_no_fuel_has_no_delta_v() {
+        let fuel = FuelContainer::<Diesel>::new(0);
+        let stage = Stage::new(50.0, fuel, OmniGenerator::<100>).unwrap();
+        assert_eq!(stage.delta_v(), 0.0);
+    }
+
+    #[test]
+    fn stage_delta_v_follows_the_tsiolkovsky_equation() {
+        let fuel = FuelContainer::<Diesel>::new(1055);
+        let stage = Stage::new(50.0, fuel, OmniGenerator::<100>).unwrap();
+        assert!((stage.delta_v() - 1421.9454117908315).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rocket_total_delta_v_carries_later_stages_as_payload() {
+        let mut rocket = Rocket::new();
+        rocket.add_stage(
+            Stage::new(50.0, FuelContainer::<Diesel>::new(1055), OmniGenerator::<100>).unwrap(),
+        );
+        rocket.add_stage(
+            Stage::new(30.0, FuelContainer::<Diesel>::new(1055), OmniGenerator::<100>).unwrap(),
+        );
+
+        // Stage 0 fires with stage 1's dry mass still attached; stage 1 fires alone once stage
+        // 0 has been dropped.
+        assert!((rocket.total_delta_v() - 2866.5568426446825).abs() < 1e-9);
     }
 }